@@ -0,0 +1,128 @@
+use std::ops::{ControlFlow, Try};
+
+pub trait TryAllAnyExt<Tryable: Try<Output = bool>>: Iterator {
+    /// Tests if every element of the iterator matches a predicate, stopping
+    /// at the first error.
+    ///
+    /// `try_all()` is like [`Iterator::all`], but the predicate may fail, in
+    /// which case the search stops and the error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_try::TryAllAnyExt;
+    ///
+    /// let a = ["1", "2", "3", "4", "5"];
+    ///
+    /// let is_positive = |s: &str| -> Result<bool, std::num::ParseIntError> {
+    ///     Ok(s.parse::<i32>()? > 0)
+    /// };
+    ///
+    /// let result = a.iter().try_all(|&s| is_positive(s));
+    /// assert_eq!(result, Ok(true));
+    ///
+    /// let result = a.iter().try_all(|&s| is_positive(&s[1..]));
+    /// assert!(result.is_err());
+    /// ```
+    fn try_all<F, R>(&mut self, mut f: F) -> Tryable
+    where
+        Self: Sized,
+        R: Try<Output = bool, Residual = Tryable::Residual>,
+        F: FnMut(Self::Item) -> R,
+    {
+        let done = self.try_for_each(move |x| match f(x).branch() {
+            ControlFlow::Continue(true) => ControlFlow::Continue(()),
+            ControlFlow::Continue(false) => ControlFlow::Break(Ok(false)),
+            ControlFlow::Break(residual) => ControlFlow::Break(Err(residual)),
+        });
+        match done {
+            ControlFlow::Continue(()) => Tryable::from_output(true),
+            ControlFlow::Break(Ok(b)) => Tryable::from_output(b),
+            ControlFlow::Break(Err(residual)) => Tryable::from_residual(residual),
+        }
+    }
+
+    /// Tests if any element of the iterator matches a predicate, stopping at
+    /// the first error.
+    ///
+    /// `try_any()` is like [`Iterator::any`], but the predicate may fail, in
+    /// which case the search stops and the error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_try::TryAllAnyExt;
+    ///
+    /// let a = ["1", "2", "3", "4", "5"];
+    ///
+    /// let is_positive = |s: &str| -> Result<bool, std::num::ParseIntError> {
+    ///     Ok(s.parse::<i32>()? > 0)
+    /// };
+    ///
+    /// let result = a.iter().try_any(|&s| is_positive(s));
+    /// assert_eq!(result, Ok(true));
+    ///
+    /// let result = a.iter().try_any(|&s| is_positive(&s[1..]));
+    /// assert!(result.is_err());
+    /// ```
+    fn try_any<F, R>(&mut self, mut f: F) -> Tryable
+    where
+        Self: Sized,
+        R: Try<Output = bool, Residual = Tryable::Residual>,
+        F: FnMut(Self::Item) -> R,
+    {
+        let done = self.try_for_each(move |x| match f(x).branch() {
+            ControlFlow::Continue(false) => ControlFlow::Continue(()),
+            ControlFlow::Continue(true) => ControlFlow::Break(Ok(true)),
+            ControlFlow::Break(residual) => ControlFlow::Break(Err(residual)),
+        });
+        match done {
+            ControlFlow::Continue(()) => Tryable::from_output(false),
+            ControlFlow::Break(Ok(b)) => Tryable::from_output(b),
+            ControlFlow::Break(Err(residual)) => Tryable::from_residual(residual),
+        }
+    }
+}
+
+/// We only provide a generic implementation for Result type to make the API
+/// usable without requiring use to provide the type at every use to
+/// disambiguate the inference and allow for elegant use with `?` operator.
+impl<I: Iterator<Item = Item>, Item, E> TryAllAnyExt<Result<bool, E>> for I {}
+
+#[test]
+fn test_try_all() {
+    let xs: &[isize] = &[];
+    assert_eq!(xs.iter().try_all(testfn), Ok(true));
+    let xs: &[isize] = &[1, 1, 1];
+    assert_eq!(xs.iter().try_all(testfn), Ok(true));
+    let xs: &[isize] = &[1, 2, 1];
+    assert_eq!(xs.iter().try_all(testfn), Ok(false));
+    let xs: &[isize] = &[1, 4, 1];
+    assert_eq!(xs.iter().try_all(testfn), Err(()));
+
+    fn testfn(x: &isize) -> Result<bool, ()> {
+        if *x == 4 {
+            return Err(());
+        }
+        Ok(*x == 1)
+    }
+}
+
+#[test]
+fn test_try_any() {
+    let xs: &[isize] = &[];
+    assert_eq!(xs.iter().try_any(testfn), Ok(false));
+    let xs: &[isize] = &[1, 3, 1];
+    assert_eq!(xs.iter().try_any(testfn), Ok(false));
+    let xs: &[isize] = &[1, 2, 1];
+    assert_eq!(xs.iter().try_any(testfn), Ok(true));
+    let xs: &[isize] = &[1, 4, 1];
+    assert_eq!(xs.iter().try_any(testfn), Err(()));
+
+    fn testfn(x: &isize) -> Result<bool, ()> {
+        if *x == 4 {
+            return Err(());
+        }
+        Ok(*x == 2)
+    }
+}
@@ -1,6 +1,6 @@
-use std::ops::Try;
+use std::ops::{ControlFlow, Try};
 
-pub trait TryFindExt<Tryable: Try<Ok = Option<Self::Item>>>: Iterator {
+pub trait TryFindExt<Tryable: Try<Output = Option<Self::Item>>>: Iterator {
     /// Applies function to the elements of iterator and returns
     /// the first non-none result or the first error.
     ///
@@ -25,29 +25,25 @@ pub trait TryFindExt<Tryable: Try<Ok = Option<Self::Item>>>: Iterator {
     fn try_find<F, R>(&mut self, mut f: F) -> Tryable
     where
         Self: Sized,
-        R: Try<Ok = bool, Error = Tryable::Error>,
+        R: Try<Output = bool, Residual = Tryable::Residual>,
         F: FnMut(&Self::Item) -> R,
     {
-        let done = self.try_for_each(move |x| match f(&x).into_result() {
-            Ok(false) => Ok(()),
-            Ok(true) => Err(Ok(x)),
-            Err(x) => Err(Err(x)),
+        let done = self.try_for_each(move |x| match f(&x).branch() {
+            ControlFlow::Continue(false) => ControlFlow::Continue(()),
+            ControlFlow::Continue(true) => ControlFlow::Break(Ok(x)),
+            ControlFlow::Break(residual) => ControlFlow::Break(Err(residual)),
         });
-        let result = match done {
-            Ok(..) => None,
-            Err(x) => Some(x),
-        }
-        .transpose();
-        match result {
-            Ok(x) => Tryable::from_ok(x),
-            Err(x) => Tryable::from_error(x),
+        match done {
+            ControlFlow::Continue(()) => Tryable::from_output(None),
+            ControlFlow::Break(Ok(x)) => Tryable::from_output(Some(x)),
+            ControlFlow::Break(Err(residual)) => Tryable::from_residual(residual),
         }
     }
 }
 
-/// We only provide gneeric implementation for Result type to make the API
+/// We only provide a generic implementation for Result type to make the API
 /// usable without requiring use to provide the type at every use to
-/// disambiguate the inferrence and allow for elegant use with `?` operator.
+/// disambiguate the inference and allow for elegant use with `?` operator.
 impl<I: Iterator<Item = Item>, Item, E> TryFindExt<Result<Option<Item>, E>> for I {}
 
 #[test]
@@ -0,0 +1,78 @@
+use std::ops::ControlFlow;
+
+/// Searches for an element inside a fallible iterator, one whose `Item` is
+/// itself a `Result`, using a predicate that can itself fail.
+///
+/// `Option<Result<T, E>>` implements [`std::ops::Try`] only as an `Option<T>`:
+/// its `Output` is the whole `Result<T, E>` and its `Residual` is `None`, so it
+/// does not fit the `Tryable: Try<Output = Option<_>>` bound that
+/// [`crate::TryFindExt::try_find`] is parameterized over. Instead this is a
+/// dedicated method for exactly this return shape, built on the same
+/// `ControlFlow` short-circuiting the other `try_*` methods use.
+pub trait TryFindInResultExt<Item, E>: Iterator<Item = Result<Item, E>> {
+    /// Applies a fallible predicate to the `Ok` elements of the iterator and
+    /// returns the first match, the first error (whether it comes from the
+    /// iterator itself or is propagated out of the predicate), or `None` if
+    /// the iterator is exhausted without a match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_try::TryFindInResultExt;
+    ///
+    /// fn find_first_big<'a>(strs: &[&'a str], threshold: i32) -> Option<Result<&'a str, std::num::ParseIntError>> {
+    ///     strs.iter().map(|&s| Ok(s)).try_find_in_result(|s: &&str| {
+    ///         // `?` here bails the whole search out, not just this one call.
+    ///         let n: i32 = s.parse()?;
+    ///         Ok(n > threshold)
+    ///     })
+    /// }
+    ///
+    /// assert_eq!(find_first_big(&["1", "3", "40"], 10), Some(Ok("40")));
+    /// assert_eq!(find_first_big(&["1", "2", "3"], 10), None);
+    /// assert!(find_first_big(&["1", "lol", "40"], 10).unwrap().is_err());
+    /// ```
+    fn try_find_in_result<P>(&mut self, mut predicate: P) -> Option<Result<Item, E>>
+    where
+        Self: Sized,
+        P: FnMut(&Item) -> Result<bool, E>,
+    {
+        let done = self.try_fold((), |(), x| match x {
+            Ok(item) => match predicate(&item) {
+                Ok(false) => ControlFlow::Continue(()),
+                Ok(true) => ControlFlow::Break(Some(Ok(item))),
+                Err(e) => ControlFlow::Break(Some(Err(e))),
+            },
+            Err(e) => ControlFlow::Break(Some(Err(e))),
+        });
+        match done {
+            ControlFlow::Continue(()) => None,
+            ControlFlow::Break(result) => result,
+        }
+    }
+}
+
+impl<I: Iterator<Item = Result<Item, E>>, Item, E> TryFindInResultExt<Item, E> for I {}
+
+#[test]
+fn test_try_find_in_result() {
+    let xs: Vec<Result<isize, ()>> = vec![];
+    assert_eq!(xs.into_iter().try_find_in_result(testfn), None);
+    let xs: Vec<Result<isize, ()>> = vec![Ok(1), Ok(2), Ok(3), Ok(4)];
+    assert_eq!(xs.into_iter().try_find_in_result(testfn), Some(Ok(2)));
+    let xs: Vec<Result<isize, ()>> = vec![Ok(1), Err(()), Ok(2)];
+    assert_eq!(xs.into_iter().try_find_in_result(testfn), Some(Err(())));
+    let xs: Vec<Result<isize, ()>> = vec![Ok(1), Ok(5), Ok(2)];
+    assert_eq!(xs.into_iter().try_find_in_result(testfn_fallible), Some(Err(())));
+
+    fn testfn(x: &isize) -> Result<bool, ()> {
+        Ok(*x == 2)
+    }
+
+    fn testfn_fallible(x: &isize) -> Result<bool, ()> {
+        if *x == 5 {
+            return Err(());
+        }
+        Ok(*x == 2)
+    }
+}
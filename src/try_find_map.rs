@@ -0,0 +1,81 @@
+use std::ops::{ControlFlow, Try};
+
+pub trait TryFindMapExt<Tryable: Try<Output = Option<Self::FindMapOk>>>: Iterator {
+    /// The type produced by a successful `try_find_map` lookup.
+    type FindMapOk;
+
+    /// Applies function to the elements of iterator and returns the first
+    /// non-none result, or the first error.
+    ///
+    /// `try_find_map()` is like [`Iterator::find_map`], but the function may
+    /// fail, in which case the search stops and the error is returned.
+    ///
+    /// Unlike [`crate::TryFindExt::try_find`], which returns the original item,
+    /// `try_find_map()` returns the value produced by `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_try::TryFindMapExt;
+    ///
+    /// let a = ["lol", "2", "NaN", "5"];
+    ///
+    /// let result = a.iter().try_find_map(|s| -> Result<_, std::num::ParseIntError> {
+    ///     match s.parse::<i32>() {
+    ///         Ok(n) => Ok(Some(n)),
+    ///         Err(_) => Ok(None),
+    ///     }
+    /// });
+    /// assert_eq!(result, Ok(Some(2)));
+    /// ```
+    fn try_find_map<F, R>(&mut self, mut f: F) -> Tryable
+    where
+        Self: Sized,
+        R: Try<Output = Option<Self::FindMapOk>, Residual = Tryable::Residual>,
+        F: FnMut(Self::Item) -> R,
+    {
+        let done = self.try_for_each(move |x| match f(x).branch() {
+            ControlFlow::Continue(None) => ControlFlow::Continue(()),
+            ControlFlow::Continue(Some(u)) => ControlFlow::Break(Ok(u)),
+            ControlFlow::Break(residual) => ControlFlow::Break(Err(residual)),
+        });
+        match done {
+            ControlFlow::Continue(()) => Tryable::from_output(None),
+            ControlFlow::Break(Ok(u)) => Tryable::from_output(Some(u)),
+            ControlFlow::Break(Err(residual)) => Tryable::from_residual(residual),
+        }
+    }
+}
+
+/// We only provide a generic implementation for Result type to make the API
+/// usable without requiring use to provide the type at every use to
+/// disambiguate the inference and allow for elegant use with `?` operator.
+impl<I: Iterator, U, E> TryFindMapExt<Result<Option<U>, E>> for I {
+    type FindMapOk = U;
+}
+
+#[test]
+fn test_try_find_map() {
+    let xs: &[isize] = &[];
+    assert_eq!(xs.iter().try_find_map(testfn), Ok(None));
+    let xs: &[isize] = &[1, 2, 3, 4];
+    assert_eq!(xs.iter().try_find_map(testfn), Ok(Some(4)));
+    let xs: &[isize] = &[1, 3, 4];
+    assert_eq!(xs.iter().try_find_map(testfn), Err(()));
+
+    let xs: &[isize] = &[1, 2, 3, 4, 5, 6, 7];
+    let mut iter = xs.iter();
+    assert_eq!(iter.try_find_map(testfn), Ok(Some(4)));
+    assert_eq!(iter.try_find_map(testfn), Err(()));
+    assert_eq!(iter.next(), Some(&5));
+
+    fn testfn(x: &isize) -> Result<Option<isize>, ()> {
+        if *x == 2 {
+            return Ok(Some(x * 2));
+        }
+        if *x == 4 {
+            return Err(());
+        }
+        Ok(None)
+    }
+}
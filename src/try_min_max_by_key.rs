@@ -0,0 +1,138 @@
+use std::ops::{ControlFlow, Try};
+
+pub trait TryMinMaxByKeyExt<Tryable: Try<Output = Option<Self::Item>>>: Iterator {
+    /// Returns the element that gives the minimum value from the
+    /// fallible specified function.
+    ///
+    /// `try_min_by_key()` is like [`Iterator::min_by_key`], but the key
+    /// function may fail, in which case the search stops and the error is
+    /// returned.
+    ///
+    /// If several elements are equally minimum, the first element is
+    /// returned. If the iterator is empty, `Ok(None)` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_try::TryMinMaxByKeyExt;
+    ///
+    /// let a = ["1", "22", "333"];
+    /// let result = a.iter().try_min_by_key(|s| s.parse::<i32>());
+    /// assert_eq!(result, Ok(Some(&"1")));
+    ///
+    /// let a = ["1", "lol", "333"];
+    /// let result = a.iter().try_min_by_key(|s| s.parse::<i32>());
+    /// assert!(result.is_err());
+    /// ```
+    fn try_min_by_key<F, K, R>(&mut self, mut f: F) -> Tryable
+    where
+        Self: Sized,
+        K: Ord,
+        R: Try<Output = K, Residual = Tryable::Residual>,
+        F: FnMut(&Self::Item) -> R,
+    {
+        let done = self.try_fold(None, |best: Option<(K, Self::Item)>, x| match f(&x).branch() {
+            ControlFlow::Continue(key) => ControlFlow::Continue(
+                if matches!(&best, Some((best_key, _)) if *best_key <= key) {
+                    best
+                } else {
+                    Some((key, x))
+                },
+            ),
+            ControlFlow::Break(residual) => ControlFlow::Break(residual),
+        });
+        match done {
+            ControlFlow::Continue(best) => Tryable::from_output(best.map(|(_, x)| x)),
+            ControlFlow::Break(residual) => Tryable::from_residual(residual),
+        }
+    }
+
+    /// Returns the element that gives the maximum value from the
+    /// fallible specified function.
+    ///
+    /// `try_max_by_key()` is like [`Iterator::max_by_key`], but the key
+    /// function may fail, in which case the search stops and the error is
+    /// returned.
+    ///
+    /// If several elements are equally maximum, the last element is
+    /// returned. If the iterator is empty, `Ok(None)` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_try::TryMinMaxByKeyExt;
+    ///
+    /// let a = ["1", "22", "333"];
+    /// let result = a.iter().try_max_by_key(|s| s.parse::<i32>());
+    /// assert_eq!(result, Ok(Some(&"333")));
+    ///
+    /// let a = ["1", "lol", "333"];
+    /// let result = a.iter().try_max_by_key(|s| s.parse::<i32>());
+    /// assert!(result.is_err());
+    /// ```
+    fn try_max_by_key<F, K, R>(&mut self, mut f: F) -> Tryable
+    where
+        Self: Sized,
+        K: Ord,
+        R: Try<Output = K, Residual = Tryable::Residual>,
+        F: FnMut(&Self::Item) -> R,
+    {
+        let done = self.try_fold(None, |best: Option<(K, Self::Item)>, x| match f(&x).branch() {
+            ControlFlow::Continue(key) => ControlFlow::Continue(
+                if matches!(&best, Some((best_key, _)) if *best_key > key) {
+                    best
+                } else {
+                    Some((key, x))
+                },
+            ),
+            ControlFlow::Break(residual) => ControlFlow::Break(residual),
+        });
+        match done {
+            ControlFlow::Continue(best) => Tryable::from_output(best.map(|(_, x)| x)),
+            ControlFlow::Break(residual) => Tryable::from_residual(residual),
+        }
+    }
+}
+
+/// We only provide a generic implementation for Result type to make the API
+/// usable without requiring use to provide the type at every use to
+/// disambiguate the inference and allow for elegant use with `?` operator.
+impl<I: Iterator<Item = Item>, Item, E> TryMinMaxByKeyExt<Result<Option<Item>, E>> for I {}
+
+#[test]
+fn test_try_min_by_key() {
+    let xs: &[isize] = &[];
+    assert_eq!(xs.iter().try_min_by_key(testfn), Ok(None));
+    let xs: &[isize] = &[3, 1, 2, 1];
+    assert_eq!(xs.iter().try_min_by_key(testfn), Ok(Some(&1)));
+    let xs: &[isize] = &[3, 4, 2];
+    assert_eq!(xs.iter().try_min_by_key(testfn), Err(()));
+
+    fn testfn(x: &&isize) -> Result<isize, ()> {
+        if **x == 4 {
+            return Err(());
+        }
+        Ok(**x)
+    }
+}
+
+#[test]
+fn test_try_max_by_key() {
+    let xs: &[isize] = &[];
+    assert_eq!(xs.iter().try_max_by_key(testfn), Ok(None));
+    let xs: &[(isize, char)] = &[(1, 'a'), (3, 'b'), (2, 'c'), (3, 'd')];
+    assert_eq!(xs.iter().try_max_by_key(testfn_tuple), Ok(Some(&(3, 'd'))));
+    let xs: &[isize] = &[3, 4, 2];
+    assert_eq!(xs.iter().try_max_by_key(testfn), Err(()));
+
+    fn testfn(x: &&isize) -> Result<isize, ()> {
+        if **x == 4 {
+            return Err(());
+        }
+        Ok(**x)
+    }
+
+    fn testfn_tuple(x: &&(isize, char)) -> Result<isize, ()> {
+        Ok(x.0)
+    }
+}
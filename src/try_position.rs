@@ -0,0 +1,145 @@
+use std::ops::{ControlFlow, Try};
+
+pub trait TryPositionExt<Tryable: Try<Output = Option<usize>>>: Iterator {
+    /// Searches for an element in an iterator, returning its index.
+    ///
+    /// `try_position()` is like [`Iterator::position`], but the predicate
+    /// may fail, in which case the search stops and the error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_try::TryPositionExt;
+    ///
+    /// let a = ["1", "2", "lol", "NaN", "5"];
+    ///
+    /// let is_my_num = |s: &str, search: i32| -> Result<bool, std::num::ParseIntError> {
+    ///     Ok(s.parse::<i32>()?  == search)
+    /// };
+    ///
+    /// let result = a.iter().try_position(|&s| is_my_num(s, 2));
+    /// assert_eq!(result, Ok(Some(1)));
+    ///
+    /// let result = a.iter().try_position(|&s| is_my_num(s, 5));
+    /// assert!(result.is_err());
+    /// ```
+    fn try_position<F, R>(&mut self, mut f: F) -> Tryable
+    where
+        Self: Sized,
+        R: Try<Output = bool, Residual = Tryable::Residual>,
+        F: FnMut(Self::Item) -> R,
+    {
+        let mut index = 0;
+        let done = self.try_for_each(move |x| match f(x).branch() {
+            ControlFlow::Continue(false) => {
+                index += 1;
+                ControlFlow::Continue(())
+            }
+            ControlFlow::Continue(true) => ControlFlow::Break(Ok(index)),
+            ControlFlow::Break(residual) => ControlFlow::Break(Err(residual)),
+        });
+        match done {
+            ControlFlow::Continue(()) => Tryable::from_output(None),
+            ControlFlow::Break(Ok(index)) => Tryable::from_output(Some(index)),
+            ControlFlow::Break(Err(residual)) => Tryable::from_residual(residual),
+        }
+    }
+
+    /// Searches for an element in an iterator from the right, returning its
+    /// index.
+    ///
+    /// `try_rposition()` is like [`Iterator::rposition`], but the predicate
+    /// may fail, in which case the search stops and the error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iter_try::TryPositionExt;
+    ///
+    /// let is_my_num = |s: &str, search: i32| -> Result<bool, std::num::ParseIntError> {
+    ///     Ok(s.parse::<i32>()?  == search)
+    /// };
+    ///
+    /// let a = ["1", "2", "3"];
+    /// let result = a.iter().try_rposition(|&s| is_my_num(s, 2));
+    /// assert_eq!(result, Ok(Some(1)));
+    ///
+    /// let a = ["1", "2", "lol"];
+    /// let result = a.iter().try_rposition(|&s| is_my_num(s, 2));
+    /// assert!(result.is_err());
+    /// ```
+    fn try_rposition<F, R>(&mut self, mut f: F) -> Tryable
+    where
+        Self: Sized + ExactSizeIterator + DoubleEndedIterator,
+        R: Try<Output = bool, Residual = Tryable::Residual>,
+        F: FnMut(Self::Item) -> R,
+    {
+        let mut index = self.len();
+        let done = self.try_rfold((), move |_, x| {
+            index -= 1;
+            match f(x).branch() {
+                ControlFlow::Continue(false) => ControlFlow::Continue(()),
+                ControlFlow::Continue(true) => ControlFlow::Break(Ok(index)),
+                ControlFlow::Break(residual) => ControlFlow::Break(Err(residual)),
+            }
+        });
+        match done {
+            ControlFlow::Continue(()) => Tryable::from_output(None),
+            ControlFlow::Break(Ok(index)) => Tryable::from_output(Some(index)),
+            ControlFlow::Break(Err(residual)) => Tryable::from_residual(residual),
+        }
+    }
+}
+
+/// We only provide a generic implementation for Result type to make the API
+/// usable without requiring use to provide the type at every use to
+/// disambiguate the inference and allow for elegant use with `?` operator.
+impl<I: Iterator<Item = Item>, Item, E> TryPositionExt<Result<Option<usize>, E>> for I {}
+
+#[test]
+fn test_try_position() {
+    let xs: &[isize] = &[];
+    assert_eq!(xs.iter().try_position(testfn), Ok(None));
+    let xs: &[isize] = &[1, 2, 3, 4];
+    assert_eq!(xs.iter().try_position(testfn), Ok(Some(1)));
+    let xs: &[isize] = &[1, 3, 4];
+    assert_eq!(xs.iter().try_position(testfn), Err(()));
+
+    let xs: &[isize] = &[1, 2, 3, 4, 5, 6, 7];
+    let mut iter = xs.iter();
+    assert_eq!(iter.try_position(testfn), Ok(Some(1)));
+    assert_eq!(iter.try_position(testfn), Err(()));
+    assert_eq!(iter.next(), Some(&5));
+
+    fn testfn(x: &isize) -> Result<bool, ()> {
+        if *x == 2 {
+            return Ok(true);
+        }
+        if *x == 4 {
+            return Err(());
+        }
+        Ok(false)
+    }
+}
+
+#[test]
+fn test_try_rposition() {
+    let xs: &[isize] = &[];
+    assert_eq!(xs.iter().try_rposition(testfn), Ok(None));
+    let xs: &[isize] = &[1, 2, 3];
+    assert_eq!(xs.iter().try_rposition(testfn), Ok(Some(1)));
+    let xs: &[isize] = &[5, 6, 2];
+    assert_eq!(xs.iter().try_rposition(testfn), Ok(Some(2)));
+    let xs: &[isize] = &[1, 4, 3];
+    assert_eq!(xs.iter().try_rposition(testfn), Err(()));
+
+    fn testfn(x: &isize) -> Result<bool, ()> {
+        if *x == 2 {
+            return Ok(true);
+        }
+        if *x == 4 {
+            return Err(());
+        }
+        Ok(false)
+    }
+}